@@ -1,3 +1,5 @@
+use rand::{Rng, RngCore};
+
 use crate::{
     ray::Ray,
     vec3::{Point3, Vec3},
@@ -12,9 +14,12 @@ pub struct Camera {
     u: Vec3,
     v: Vec3,
     lens_radius: f64,
+    time0: f64, // shutter open
+    time1: f64, // shutter close
 }
 
 impl Camera {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         look_from: Point3,
         look_at: Point3,
@@ -23,6 +28,8 @@ impl Camera {
         aspect_ratio: f64,
         aperture: f64,
         focus_distance: f64,
+        time0: f64,
+        time1: f64,
     ) -> Self {
         let h = (vertical_fov_degrees.to_radians() / 2.0).tan();
 
@@ -50,15 +57,19 @@ impl Camera {
             u,
             v,
             lens_radius,
+            time0,
+            time1,
         }
     }
 
-    pub fn get_ray(&self, s: f64, t: f64) -> Ray {
-        let rd = Vec3::random_in_unit_disk() * self.lens_radius;
+    pub fn get_ray(&self, s: f64, t: f64, rng: &mut dyn RngCore) -> Ray {
+        let rd = Vec3::random_in_unit_disk(rng) * self.lens_radius;
         let offset = self.u * rd.x + self.v + rd.y;
+        let time = rng.gen_range(self.time0..=self.time1); // still camera when time0 == time1
         Ray::new(
             self.origin + offset,
             self.lower_left_corner + self.horizontal * s + self.vertical * t - self.origin - offset,
+            time,
         )
     }
 }
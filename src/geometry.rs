@@ -4,6 +4,51 @@ use crate::vec3::Point3;
 
 use crate::hit::{HitRecord, Hittable};
 
+// shared ray/sphere intersection math, used by both Sphere and MovingSphere (which only differ
+// in how they resolve `centre` for a given ray)
+fn sphere_hit<'a>(
+    ray: &Ray,
+    centre: Point3,
+    radius: f64,
+    t_min: f64,
+    t_max: f64,
+    material: &'a dyn Material,
+) -> Option<HitRecord<'a>> {
+    let oc = ray.origin - centre;
+    let a = ray.direction.length_squared();
+    let half_b = ray.direction.dot(oc);
+    let c = oc.length_squared() - radius * radius;
+    let discriminant = half_b * half_b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let d = discriminant.sqrt();
+    let mut root = (-half_b - d) / a;
+    // checking both roots, for closest within bounds
+    if root < t_min || root > t_max {
+        root = (-half_b + d) / a;
+        if root < t_min || root > t_max {
+            return None;
+        }
+    }
+    let point = ray.at(root);
+    let normal = (point - centre) / radius; // divide by radius same as finding unit vector
+    let (normal, hit_from_inside) = if normal.dot(ray.direction) > 0.0 {
+        // inside sphere, since pointing in same direction
+        (-normal, true)
+    } else {
+        // outside sphere
+        (normal, false)
+    };
+    Some(HitRecord::new(
+        point,
+        normal,
+        root,
+        hit_from_inside,
+        material,
+    ))
+}
+
 pub struct Sphere {
     centre: Point3,
     radius: f64,
@@ -22,38 +67,64 @@ impl Sphere {
 
 impl Hittable for Sphere {
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
-        let oc = ray.origin - self.centre;
-        let a = ray.direction.length_squared();
-        let half_b = ray.direction.dot(oc);
-        let c = oc.length_squared() - self.radius * self.radius;
-        let discriminant = half_b * half_b - a * c;
-        if discriminant < 0.0 {
-            return None;
-        }
-        let d = discriminant.sqrt();
-        let mut root = (-half_b - d) / a;
-        // checking both roots, for closest within bounds
-        if root < t_min || root > t_max {
-            root = (-half_b + d) / a;
-            if root < t_min || root > t_max {
-                return None;
-            }
+        sphere_hit(
+            ray,
+            self.centre,
+            self.radius,
+            t_min,
+            t_max,
+            self.material.as_ref(),
+        )
+    }
+}
+
+/// A sphere whose centre moves linearly between `centre0` (at `time0`) and `centre1` (at `time1`),
+/// for motion blur.
+pub struct MovingSphere {
+    centre0: Point3,
+    centre1: Point3,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    material: Box<dyn Material>,
+}
+
+impl MovingSphere {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        centre0: Point3,
+        centre1: Point3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Box<dyn Material>,
+    ) -> Self {
+        Self {
+            centre0,
+            centre1,
+            time0,
+            time1,
+            radius,
+            material,
         }
-        let point = ray.at(root);
-        let normal = (point - self.centre) / self.radius; // divide by radius same as finding unit vector
-        let (normal, hit_from_inside) = if normal.dot(ray.direction) > 0.0 {
-            // inside sphere, since pointing in same direction
-            (-normal, true)
-        } else {
-            // outside sphere
-            (normal, false)
-        };
-        Some(HitRecord::new(
-            point,
-            normal,
-            root,
-            hit_from_inside,
+    }
+
+    fn centre(&self, time: f64) -> Point3 {
+        self.centre0
+            + (self.centre1 - self.centre0) * ((time - self.time0) / (self.time1 - self.time0))
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let centre = self.centre(ray.time);
+        sphere_hit(
+            ray,
+            centre,
+            self.radius,
+            t_min,
+            t_max,
             self.material.as_ref(),
-        ))
+        )
     }
 }
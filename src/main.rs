@@ -11,10 +11,11 @@ use std::{
     time::Instant,
 };
 
-use geometry::Sphere;
+use geometry::{MovingSphere, Sphere};
 use hit::Hittable;
-use material::{Dielectric, Lambertian, Material, Metal};
-use rand::{thread_rng, Rng};
+use material::{Dielectric, Lambertian, Metal};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_pcg::Pcg64;
 use ray::Ray;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use vec3::{Point3, Rgb, Vec3};
@@ -23,8 +24,15 @@ use crate::camera::Camera;
 
 const WHITE: Rgb = Rgb::from_scalar(1.0);
 const LIGHT_BLUE: Rgb = Rgb::new(0.5, 0.7, 1.0);
-
-fn ray_colour(ray: &Ray, depth: u32, world: &[Box<dyn Hittable + Sync>]) -> Rgb {
+// global seed for per-pixel RNGs, so renders are reproducible regardless of thread scheduling
+const RNG_SEED: u64 = 0;
+
+fn ray_colour(
+    ray: &Ray,
+    depth: u32,
+    world: &[Box<dyn Hittable + Sync>],
+    rng: &mut dyn RngCore,
+) -> Rgb {
     match depth {
         0 => Rgb::default(), // max bounces, no colour
         _ => world
@@ -34,9 +42,9 @@ fn ray_colour(ray: &Ray, depth: u32, world: &[Box<dyn Hittable + Sync>]) -> Rgb
             .min_by(|a, b| a.t.partial_cmp(&b.t).unwrap())
             .map(|hr| {
                 hr.material
-                    .scatter(ray, &hr)
+                    .scatter(ray, &hr, rng)
                     .map(|(scattered, attenuation)| {
-                        attenuation * ray_colour(&scattered, depth - 1, world)
+                        attenuation * ray_colour(&scattered, depth - 1, world, rng)
                     })
                     .unwrap_or_default()
             })
@@ -47,8 +55,14 @@ fn ray_colour(ray: &Ray, depth: u32, world: &[Box<dyn Hittable + Sync>]) -> Rgb
     }
 }
 
+// deterministic per-pixel seed, so the rayon-parallel render loop is reproducible
+fn pixel_seed(i: u32, j: u32) -> u64 {
+    RNG_SEED ^ ((i as u64) << 32 | j as u64)
+}
+
 fn random_scene() -> Vec<Box<dyn Hittable + Sync>> {
-    let mut rng = thread_rng();
+    // seeded from RNG_SEED too, so the whole render (scene included) is reproducible
+    let mut rng = Pcg64::seed_from_u64(RNG_SEED);
 
     let mut world: Vec<Box<dyn Hittable + Sync>> = vec![];
     let ground = Box::new(Lambertian::new(Rgb::new(0.5, 0.5, 0.5)));
@@ -68,20 +82,25 @@ fn random_scene() -> Vec<Box<dyn Hittable + Sync>> {
             );
 
             if (centre - Point3::new(4.0, 0.2, 0.0)).length() > 0.9 {
-                let material: Box<dyn Material + Sync> = if material_choice < 0.8 {
-                    // diffuse
-                    let albedo = Rgb::random() * Rgb::random();
-                    Box::new(Lambertian::new(albedo))
+                if material_choice < 0.8 {
+                    // diffuse, moving straight up by a random amount over the shutter for motion blur
+                    let albedo = Rgb::random(&mut rng) * Rgb::random(&mut rng);
+                    let material = Box::new(Lambertian::new(albedo));
+                    let centre_end = centre + Point3::new(0.0, rng.gen_range(0.0..0.5), 0.0);
+                    world.push(Box::new(MovingSphere::new(
+                        centre, centre_end, 0.0, 1.0, 0.2, material,
+                    )));
                 } else if material_choice < 0.95 {
                     // metal
-                    let albedo = Rgb::random_in_range(0.5, 1.0);
+                    let albedo = Rgb::random_in_range(&mut rng, 0.5, 1.0);
                     let fuzziness = rng.gen_range(0.0..0.5);
-                    Box::new(Metal::new(albedo, fuzziness))
+                    let material = Box::new(Metal::new(albedo, fuzziness));
+                    world.push(Box::new(Sphere::new(centre, 0.2, material)));
                 } else {
                     // glass
-                    Box::new(Dielectric::new(1.5))
+                    let material = Box::new(Dielectric::new(1.5));
+                    world.push(Box::new(Sphere::new(centre, 0.2, material)));
                 };
-                world.push(Box::new(Sphere::new(centre, 0.2, material)));
             }
         }
     }
@@ -135,6 +154,8 @@ fn main() {
     let up = Vec3::new(0.0, 1.0, 0.0);
     let aperture = 0.1;
     let focus_distance = 10.0;
+    let time0 = 0.0;
+    let time1 = 1.0;
     let camera = Camera::new(
         look_from,
         look_at,
@@ -143,6 +164,8 @@ fn main() {
         aspect_ratio,
         aperture,
         focus_distance,
+        time0,
+        time1,
     );
 
     let world = random_scene();
@@ -151,14 +174,14 @@ fn main() {
         let row_bytes = (0..image_width)
             .into_par_iter()
             .flat_map(|i| {
-                let mut rng = thread_rng();
+                let mut rng = Pcg64::seed_from_u64(pixel_seed(i, j));
                 let mut colour = Vec3::default();
                 // multiple rays per pixel for AA
                 for _ in 0..samples_per_pixel {
                     let u = (i as f64 + rng.gen::<f64>()) / ((image_width - 1) as f64);
                     let v = (j as f64 + rng.gen::<f64>()) / ((image_height - 1) as f64);
-                    let ray = camera.get_ray(u, v);
-                    colour += ray_colour(&ray, max_depth, &world);
+                    let ray = camera.get_ray(u, v, &mut rng);
+                    colour += ray_colour(&ray, max_depth, &world, &mut rng);
                 }
                 colour /= samples_per_pixel as f64;
                 // sqrt for gamma correction, gamma = 2.0 (raise colour to 1/gamma)
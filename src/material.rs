@@ -1,4 +1,4 @@
-use rand::{thread_rng, Rng};
+use rand::{Rng, RngCore};
 
 use crate::{
     hit::HitRecord,
@@ -8,7 +8,7 @@ use crate::{
 
 pub trait Material: Sync {
     // returns scattered ray (and colour attenuation), if any
-    fn scatter(&self, ray: &Ray, hr: &HitRecord) -> Option<(Ray, Rgb)>;
+    fn scatter(&self, ray: &Ray, hr: &HitRecord, rng: &mut dyn RngCore) -> Option<(Ray, Rgb)>;
 }
 
 pub struct Lambertian {
@@ -22,18 +22,18 @@ impl Lambertian {
 }
 
 impl Material for Lambertian {
-    fn scatter(&self, _: &Ray, hr: &HitRecord) -> Option<(Ray, Rgb)> {
+    fn scatter(&self, ray: &Ray, hr: &HitRecord, rng: &mut dyn RngCore) -> Option<(Ray, Rgb)> {
         // unit circle tangent to hit point, in direction of normal (which is against ray)
         // send random ray to somewhere in this unit circle for diffuse ray collection
-        // convert random vec to unit for Lambertian distribution (pick along surface of unit sphere)
-        let scatter_dir = hr.normal + Vec3::random_in_unit_sphere().unit();
+        // pick along surface of unit sphere for Lambertian distribution
+        let scatter_dir = hr.normal + Vec3::random_unit_vector(rng);
         // in case random vec is opposite to normal = 0 vec
         let scatter_dir = if scatter_dir.near_zero() {
             hr.normal
         } else {
             scatter_dir
         };
-        Some((Ray::new(hr.point, scatter_dir), self.albedo))
+        Some((Ray::new(hr.point, scatter_dir, ray.time), self.albedo))
     }
 }
 
@@ -49,12 +49,13 @@ impl Metal {
 }
 
 impl Material for Metal {
-    fn scatter(&self, ray: &Ray, hr: &HitRecord) -> Option<(Ray, Rgb)> {
+    fn scatter(&self, ray: &Ray, hr: &HitRecord, rng: &mut dyn RngCore) -> Option<(Ray, Rgb)> {
         let reflected_dir = ray.direction.unit().reflect_across(hr.normal);
         let scatter_r = Ray::new(
             hr.point,
             // randomise endpoint of reflect a bit depending on how fuzzy material is (high fuzz = high variance/blur)
-            reflected_dir + Vec3::random_in_unit_sphere() * self.fuzziness,
+            reflected_dir + Vec3::random_in_unit_sphere(rng) * self.fuzziness,
+            ray.time,
         );
         if scatter_r.direction.dot(hr.normal) > 0.0 {
             // proper reflection
@@ -86,7 +87,7 @@ impl Dielectric {
 }
 
 impl Material for Dielectric {
-    fn scatter(&self, ray: &Ray, hr: &HitRecord) -> Option<(Ray, Rgb)> {
+    fn scatter(&self, ray: &Ray, hr: &HitRecord, rng: &mut dyn RngCore) -> Option<(Ray, Rgb)> {
         let refraction_ratio = if hr.hit_from_inside {
             self.index_of_refraction
         } else {
@@ -101,13 +102,16 @@ impl Material for Dielectric {
 
         let cannot_refract = refraction_ratio * sin_theta > 1.0;
         let refracted_dir = if cannot_refract
-            || Dielectric::reflectance(cos_theta, refraction_ratio) > thread_rng().gen()
+            || Dielectric::reflectance(cos_theta, refraction_ratio) > rng.gen()
         {
             unit_dir.reflect_across(hr.normal)
         } else {
             unit_dir.refract_across(hr.normal, refraction_ratio)
         };
 
-        Some((Ray::new(hr.point, refracted_dir), Vec3::from_scalar(1.0)))
+        Some((
+            Ray::new(hr.point, refracted_dir, ray.time),
+            Vec3::from_scalar(1.0),
+        ))
     }
 }
@@ -0,0 +1,21 @@
+use crate::vec3::{Point3, Vec3};
+
+pub struct Ray {
+    pub origin: Point3,
+    pub direction: Vec3,
+    pub time: f64, // when during the shutter this ray was cast, for motion blur
+}
+
+impl Ray {
+    pub const fn new(origin: Point3, direction: Vec3, time: f64) -> Self {
+        Self {
+            origin,
+            direction,
+            time,
+        }
+    }
+
+    pub fn at(&self, t: f64) -> Point3 {
+        self.origin + self.direction * t
+    }
+}
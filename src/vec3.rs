@@ -1,6 +1,7 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub};
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
+use rand_distr::{Distribution, UnitBall, UnitDisc, UnitSphere};
 
 pub type Point3 = Vec3;
 pub type Rgb = Vec3;
@@ -21,8 +22,7 @@ impl Vec3 {
         Self { x: v, y: v, z: v }
     }
 
-    pub fn random() -> Self {
-        let mut rng = thread_rng();
+    pub fn random(rng: &mut impl Rng) -> Self {
         Self {
             x: rng.gen(),
             y: rng.gen(),
@@ -30,8 +30,7 @@ impl Vec3 {
         }
     }
 
-    pub fn random_in_range(min: f64, max: f64) -> Self {
-        let mut rng = thread_rng();
+    pub fn random_in_range(rng: &mut impl Rng, min: f64, max: f64) -> Self {
         Self {
             x: rng.gen_range(min..max),
             y: rng.gen_range(min..max),
@@ -39,27 +38,21 @@ impl Vec3 {
         }
     }
 
-    pub fn random_in_unit_sphere() -> Self {
-        loop {
-            let v = Self::random_in_range(-1.0, 1.0);
-            if v.length_squared() <= 1.0 {
-                return v;
-            }
-        }
+    // uniform point on the unit sphere's surface, for Lambertian scatter directions
+    pub fn random_unit_vector(rng: &mut (impl Rng + ?Sized)) -> Self {
+        let [x, y, z] = UnitSphere.sample(rng);
+        Self { x, y, z }
     }
 
-    pub fn random_in_unit_disk() -> Self {
-        let mut rng = thread_rng();
-        loop {
-            let v = Self {
-                x: rng.gen_range(-1.0..1.0),
-                y: rng.gen_range(-1.0..1.0),
-                z: 0.0,
-            };
-            if v.length_squared() <= 1.0 {
-                return v;
-            }
-        }
+    // uniform point within the unit ball's volume, for Metal fuzz
+    pub fn random_in_unit_sphere(rng: &mut (impl Rng + ?Sized)) -> Self {
+        let [x, y, z] = UnitBall.sample(rng);
+        Self { x, y, z }
+    }
+
+    pub fn random_in_unit_disk(rng: &mut (impl Rng + ?Sized)) -> Self {
+        let [x, y] = UnitDisc.sample(rng);
+        Self { x, y, z: 0.0 }
     }
 
     pub fn dot(&self, rhs: Self) -> f64 {